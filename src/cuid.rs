@@ -0,0 +1,110 @@
+//! A validated newtype wrapper around a CUID2 string, following the pattern
+//! the `uuid` crate uses for its `serde_support` module: a typed id that
+//! validates once on construction instead of passing raw `String`s around.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{is_valid_cuid, CuidError, Result, MAX_LENGTH, MIN_LENGTH};
+
+/// A CUID2 string that has already been validated with
+/// [`crate::is_valid_cuid`].
+///
+/// Build one with [`Cuid::parse`] or the [`FromStr`]/[`TryFrom<String>`]
+/// impls; malformed input is rejected at construction rather than being
+/// stored and discovered later.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cuid(String);
+
+impl Cuid {
+    /// Validates `value` as a CUID2 string and wraps it.
+    pub fn parse(value: &str) -> Result<Self> {
+        if !is_valid_cuid(value, MIN_LENGTH, MAX_LENGTH) {
+            return Err(CuidError::InvalidCuid(value.to_string()));
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+
+impl fmt::Display for Cuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Cuid {
+    type Err = CuidError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl AsRef<str> for Cuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Cuid {
+    type Error = CuidError;
+
+    fn try_from(value: String) -> Result<Self> {
+        if !is_valid_cuid(&value, MIN_LENGTH, MAX_LENGTH) {
+            return Err(CuidError::InvalidCuid(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+    use super::Cuid;
+    use serde::de::Error as DeError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Serialize for Cuid {
+        fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Cuid {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+            let raw = String::deserialize(deserializer)?;
+            Cuid::parse(&raw).map_err(DeError::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate;
+
+    #[test]
+    fn test_parse_accepts_generated_cuid() {
+        let id = generate().unwrap();
+        assert!(Cuid::parse(&id).is_ok());
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_id() {
+        assert!(Cuid::parse("1abc123").is_err());
+        assert!(Cuid::parse("abc-123").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips_through_from_str() {
+        let id = generate().unwrap();
+        let cuid: Cuid = id.parse().unwrap();
+        assert_eq!(cuid.to_string(), id);
+    }
+
+    #[test]
+    fn test_try_from_string() {
+        let id = generate().unwrap();
+        let cuid = Cuid::try_from(id.clone()).unwrap();
+        assert_eq!(cuid.as_ref(), id);
+    }
+}
@@ -1,10 +1,50 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use core::cell::RefCell;
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
 use rand::rngs::OsRng;
-use rand::{Rng, SeedableRng, TryRngCore};
+use rand::{Rng, RngCore};
+#[cfg(feature = "std")]
+use rand::{SeedableRng, TryRngCore};
+#[cfg(feature = "std")]
 use rand_chacha::ChaCha20Rng;
 use sha3::{Digest, Sha3_512};
-use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
+#[cfg(feature = "std")]
+mod cuid;
+#[cfg(feature = "std")]
+mod sequential;
+#[cfg(feature = "std")]
+pub use cuid::Cuid;
+#[cfg(feature = "std")]
+pub use sequential::SequentialGenerator;
+
+/// Renders `value` as a zero-padded, lowercase base-36 string of exactly
+/// `width` characters. Used by [`Cuid2::generate_sortable`] and, behind the
+/// `std` feature, by [`SequentialGenerator`].
+pub(crate) fn render_base36(mut value: u64, width: usize) -> String {
+    let mut digits = vec!['0'; width];
+    for slot in digits.iter_mut().rev() {
+        *slot = char::from_digit((value % 36) as u32, 36).unwrap();
+        value /= 36;
+    }
+    digits.into_iter().collect()
+}
+
 /// Default length for generated CUIDs
 pub const DEFAULT_LENGTH: usize = 24;
 /// Maximum length for generated CUIDs
@@ -12,20 +52,39 @@ pub const MAX_LENGTH: usize = 32;
 /// Minimum length for valid CUIDs
 pub const MIN_LENGTH: usize = 2;
 /// Alphabet for generating random letters
-const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
-/// Counter for ensuring uniqueness
-static COUNTER: AtomicU64 = AtomicU64::new(0);
+pub(crate) const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+/// Default alphabet used for entropy and hashed segments: CUID2's own
+/// lowercase-alphanumeric character set.
+pub(crate) const DEFAULT_ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+/// Width, in base-36 digits, of the millisecond timestamp embedded by
+/// [`Cuid2::generate_sortable`]. 9 base-36 digits (36^9 milliseconds) keep
+/// the timestamp field sortable until roughly the year 5188.
+const SORTABLE_TIMESTAMP_WIDTH: usize = 9;
+/// Width, in base-36 digits, of the monotonic counter embedded by
+/// [`Cuid2::generate_sortable`] immediately after the timestamp, which
+/// breaks ties between IDs generated within the same millisecond.
+const SORTABLE_COUNTER_WIDTH: usize = 4;
+/// One past the largest tie-break sequence value that still fits in
+/// [`SORTABLE_COUNTER_WIDTH`] base-36 digits.
+const MAX_SORTABLE_SEQ: u64 = 36u64.pow(SORTABLE_COUNTER_WIDTH as u32);
+/// Minimum length accepted by [`Cuid2::generate_sortable`]: a leading letter
+/// plus the timestamp and counter fields, with no room left for entropy.
+const SORTABLE_MIN_LENGTH: usize = 1 + SORTABLE_TIMESTAMP_WIDTH + SORTABLE_COUNTER_WIDTH;
 
 /// Error type for CUID generation and validation
 #[derive(Debug)]
 pub enum CuidError {
     InvalidLength(usize, usize, usize),
+    #[cfg(feature = "std")]
     SystemTimeError(std::time::SystemTimeError),
+    #[cfg(feature = "std")]
     RandChaChaError(rand_chacha::rand_core::OsError),
+    InvalidCuid(String),
+    EmptyAlphabet,
 }
 
-impl std::fmt::Display for CuidError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for CuidError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             CuidError::InvalidLength(len, min, max) => {
                 write!(
@@ -34,24 +93,35 @@ impl std::fmt::Display for CuidError {
                     len, min, max
                 )
             }
+            #[cfg(feature = "std")]
             CuidError::SystemTimeError(err) => {
                 write!(f, "System time error: {}", err)
             }
+            #[cfg(feature = "std")]
             CuidError::RandChaChaError(err) => {
                 write!(f, "ChaCha RNG error: {}", err)
             }
+            CuidError::InvalidCuid(id) => {
+                write!(f, "Invalid CUID: {:?}", id)
+            }
+            CuidError::EmptyAlphabet => {
+                write!(f, "Alphabet must not be empty")
+            }
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for CuidError {}
 
+#[cfg(feature = "std")]
 impl From<std::time::SystemTimeError> for CuidError {
     fn from(err: std::time::SystemTimeError) -> Self {
         CuidError::SystemTimeError(err)
     }
 }
 
+#[cfg(feature = "std")]
 impl From<rand_chacha::rand_core::OsError> for CuidError {
     fn from(err: rand_chacha::rand_core::OsError) -> Self {
         CuidError::RandChaChaError(err)
@@ -59,17 +129,14 @@ impl From<rand_chacha::rand_core::OsError> for CuidError {
 }
 
 /// Result type for CUID operations
-pub type Result<T> = std::result::Result<T, CuidError>;
-
-/// Generates random alphanumeric entropy of a given length.
-fn generate_entropy(length: usize) -> Result<String> {
-    // Use OsRng to generate a random seed
-    let seed = OsRng.try_next_u64()?;
-    let mut rng = ChaCha20Rng::seed_from_u64(seed);
-
-    Ok((0..length)
-        .map(|_| char::from_digit(rng.random_range(0..36) as u32, 36).unwrap())
-        .collect())
+pub type Result<T> = core::result::Result<T, CuidError>;
+
+/// Generates random entropy of a given length, drawing characters from
+/// `alphabet` using the supplied RNG.
+fn generate_entropy<R: Rng + ?Sized>(rng: &mut R, alphabet: &[u8], length: usize) -> String {
+    (0..length)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())] as char)
+        .collect()
 }
 
 /// Computes a SHA3-512 hash and returns a truncated hexadecimal string.
@@ -81,19 +148,303 @@ fn compute_hash(input: &str, length: usize) -> String {
     hash_str[..length].to_string()
 }
 
-/// Generates a random lowercase letter.
-fn generate_random_letter() -> Result<char> {
-    // Use OsRng to generate a random seed
-    let seed = OsRng.try_next_u64()?;
-    let mut rng = ChaCha20Rng::seed_from_u64(seed);
+/// Hashes `input` with SHA3-512 and encodes `length` characters of the
+/// digest using `alphabet`, so the hashed portion of an ID can be rendered
+/// in whatever base the caller's alphabet represents.
+fn encode_hash(input: &str, alphabet: &[u8], length: usize) -> String {
+    let mut hasher = Sha3_512::new();
+    hasher.update(input.as_bytes());
+    let digest = hasher.finalize();
+
+    digest
+        .iter()
+        .cycle()
+        .take(length)
+        .map(|byte| alphabet[*byte as usize % alphabet.len()] as char)
+        .collect()
+}
+
+/// Picks a single random character from `alphabet` using the supplied RNG.
+fn generate_random_letter<R: Rng + ?Sized>(rng: &mut R, alphabet: &[u8]) -> char {
+    alphabet[rng.random_range(0..alphabet.len())] as char
+}
+
+/// Computes the default process-wide fingerprint, mixing in the machine
+/// hostname, process id, and a per-process random salt so that IDs minted on
+/// different hosts or processes diverge even if their random entropy
+/// collides.
+#[cfg(feature = "std")]
+fn default_fingerprint<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let hostname = hostname::get()
+        .ok()
+        .and_then(|name| name.into_string().ok())
+        .unwrap_or_else(|| "unknown-host".to_string());
+    let pid = std::process::id();
+    let salt = generate_entropy(rng, DEFAULT_ALPHABET, MAX_LENGTH);
+
+    let input = format!("{}{}{}", hostname, pid, salt);
+    compute_hash(&input, MAX_LENGTH)
+}
+
+/// Supplies the current time to a [`Cuid2`] generator.
+///
+/// The default, `std`-only [`SystemClock`] reads [`std::time::SystemTime`].
+/// `no_std` callers implement this trait themselves (e.g. backed by an RTC
+/// peripheral) and inject it via [`Cuid2::from_parts`].
+pub trait Clock {
+    /// Returns the current time as milliseconds since the Unix epoch.
+    fn now_millis(&self) -> Result<u128>;
+}
+
+/// The default [`Clock`], backed by [`std::time::SystemTime`].
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_millis(&self) -> Result<u128> {
+        Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis())
+    }
+}
+
+/// A reusable CUID2 generator.
+///
+/// Creating a [`Cuid2`] seeds its RNG once and computes the machine
+/// fingerprint once; every subsequent call to [`Cuid2::generate`] reuses
+/// both instead of reseeding and re-hashing a fresh fingerprint per ID,
+/// which matters for high-throughput callers.
+///
+/// [`Cuid2`] is generic over an [`RngCore`] implementation and a [`Clock`]
+/// so it can run without `std`. The `std` feature (on by default) wires up
+/// [`ChaCha20Rng`] seeded from [`OsRng`] and [`SystemClock`] behind the
+/// ergonomic [`Cuid2::new`]/[`Cuid2::with_length`] constructors; the free
+/// functions [`generate`] and [`generate_cuid`] delegate to a shared
+/// thread-local instance of that `std` configuration. `no_std` callers
+/// build a [`Cuid2`] directly from their own RNG and clock with
+/// [`Cuid2::from_parts`].
+pub struct Cuid2<R: RngCore, C: Clock> {
+    rng: RefCell<R>,
+    clock: C,
+    fingerprint: String,
+    counter: AtomicU64,
+    /// Per-millisecond tie-break sequence for [`Cuid2::generate_sortable`],
+    /// stored as `(millisecond, sequence)` and reset whenever the millisecond
+    /// advances, so it never needs to wrap within a single millisecond's
+    /// worth of ids like a truncated view of `counter` would.
+    sortable_seq: RefCell<(u128, u64)>,
+    length: usize,
+    sortable_letter: char,
+    alphabet: Vec<u8>,
+    leading_chars: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl Cuid2<ChaCha20Rng, SystemClock> {
+    /// Creates a generator that produces CUIDs of [`DEFAULT_LENGTH`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cuid2_rs::Cuid2;
+    ///
+    /// let generator = Cuid2::new().unwrap();
+    /// let id = generator.generate().unwrap();
+    /// assert_eq!(id.len(), cuid2_rs::DEFAULT_LENGTH);
+    /// ```
+    pub fn new() -> Result<Self> {
+        Self::with_length(DEFAULT_LENGTH)
+    }
+
+    /// Creates a generator that produces CUIDs of `length` characters.
+    ///
+    /// # Examples
+    /// ```
+    /// use cuid2_rs::Cuid2;
+    ///
+    /// let generator = Cuid2::with_length(16).unwrap();
+    /// let first = generator.generate_sortable(16).unwrap();
+    /// let second = generator.generate_sortable(16).unwrap();
+    /// assert!(first < second);
+    /// ```
+    pub fn with_length(length: usize) -> Result<Self> {
+        Self::build(length, None, None)
+    }
+
+    /// Creates a generator of [`DEFAULT_LENGTH`] CUIDs that uses `custom` as
+    /// its machine fingerprint instead of the hostname/pid-derived default.
+    ///
+    /// Use this in environments such as containers or WASM where the
+    /// hostname and process id aren't meaningful or distinct, so distributed
+    /// deployments can still guarantee distinct machine entropy by supplying
+    /// their own identifier (e.g. a pod name or worker id).
+    pub fn with_fingerprint(custom: &str) -> Result<Self> {
+        Self::build(DEFAULT_LENGTH, Some(custom), None)
+    }
+
+    /// Creates a generator of [`DEFAULT_LENGTH`] CUIDs drawn entirely from
+    /// `alphabet` instead of the strict CUID2 lowercase-alphanumeric set.
+    ///
+    /// Unlike the default charset, the leading character is also drawn from
+    /// `alphabet`, so the id no longer necessarily starts with a letter. Use
+    /// [`is_valid_cuid_with_alphabet`] rather than [`is_valid_cuid`] to
+    /// validate ids produced this way.
+    pub fn with_alphabet(alphabet: &[u8]) -> Result<Self> {
+        Self::build(DEFAULT_LENGTH, None, Some(alphabet.to_vec()))
+    }
+
+    fn build(
+        length: usize,
+        custom_fingerprint: Option<&str>,
+        alphabet_override: Option<Vec<u8>>,
+    ) -> Result<Self> {
+        if !(MIN_LENGTH..=MAX_LENGTH).contains(&length) {
+            return Err(CuidError::InvalidLength(length, MIN_LENGTH, MAX_LENGTH));
+        }
+        if matches!(&alphabet_override, Some(custom) if custom.is_empty()) {
+            return Err(CuidError::EmptyAlphabet);
+        }
 
-    Ok(ALPHABET[rng.random_range(0..ALPHABET.len())] as char)
+        let (alphabet, leading_chars) = match alphabet_override {
+            Some(custom) => (custom.clone(), custom),
+            None => (DEFAULT_ALPHABET.to_vec(), ALPHABET.to_vec()),
+        };
+
+        let seed = OsRng.try_next_u64()?;
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        let fingerprint = match custom_fingerprint {
+            Some(custom) => compute_hash(custom, MAX_LENGTH),
+            None => default_fingerprint(&mut rng),
+        };
+        let sortable_letter = generate_random_letter(&mut rng, &leading_chars);
+
+        Ok(Self {
+            rng: RefCell::new(rng),
+            clock: SystemClock,
+            fingerprint,
+            counter: AtomicU64::new(0),
+            sortable_seq: RefCell::new((0, 0)),
+            length,
+            sortable_letter,
+            alphabet,
+            leading_chars,
+        })
+    }
 }
 
-/// Creates a fingerprint to help prevent collisions in distributed systems.
-fn generate_fingerprint() -> Result<String> {
-    let entropy = generate_entropy(MAX_LENGTH)?;
-    Ok(compute_hash(&entropy, MAX_LENGTH))
+impl<R: RngCore, C: Clock> Cuid2<R, C> {
+    /// Builds a generator from caller-supplied randomness, a clock, and a
+    /// raw fingerprint source (pre-hashing), for `no_std` environments that
+    /// have no [`OsRng`] or [`SystemTime`] to fall back on.
+    pub fn from_parts(
+        mut rng: R,
+        clock: C,
+        length: usize,
+        fingerprint_source: &str,
+        alphabet: Option<Vec<u8>>,
+    ) -> Result<Self> {
+        if !(MIN_LENGTH..=MAX_LENGTH).contains(&length) {
+            return Err(CuidError::InvalidLength(length, MIN_LENGTH, MAX_LENGTH));
+        }
+        if matches!(&alphabet, Some(custom) if custom.is_empty()) {
+            return Err(CuidError::EmptyAlphabet);
+        }
+
+        let (alphabet, leading_chars) = match alphabet {
+            Some(custom) => (custom.clone(), custom),
+            None => (DEFAULT_ALPHABET.to_vec(), ALPHABET.to_vec()),
+        };
+
+        let fingerprint = compute_hash(fingerprint_source, MAX_LENGTH);
+        let sortable_letter = generate_random_letter(&mut rng, &leading_chars);
+
+        Ok(Self {
+            rng: RefCell::new(rng),
+            clock,
+            fingerprint,
+            counter: AtomicU64::new(0),
+            sortable_seq: RefCell::new((0, 0)),
+            length,
+            sortable_letter,
+            alphabet,
+            leading_chars,
+        })
+    }
+
+    /// Generates a new CUID, reusing this generator's RNG, fingerprint, and
+    /// counter.
+    ///
+    /// See [`Cuid2::new`] for a runnable example.
+    pub fn generate(&self) -> Result<String> {
+        let mut rng = self.rng.borrow_mut();
+
+        let first_char = generate_random_letter(&mut *rng, &self.leading_chars);
+        let timestamp = self.clock.now_millis()?.to_string();
+        let counter_value = self.counter.fetch_add(1, Ordering::SeqCst).to_string();
+        let salt = generate_entropy(&mut *rng, &self.alphabet, self.length);
+
+        let hash_input = format!("{}{}{}{}", timestamp, salt, counter_value, self.fingerprint);
+        let hashed = encode_hash(&hash_input, &self.alphabet, self.length);
+
+        Ok(format!("{}{}", first_char, &hashed[1..self.length]))
+    }
+
+    /// Generates a CUID whose leading fields make successive IDs sort
+    /// lexicographically in creation order, unlike [`Cuid2::generate`],
+    /// which hashes the timestamp away.
+    ///
+    /// The id is `{letter}{timestamp}{counter}{hashed entropy}`. The leading
+    /// letter is fixed per generator (chosen once at construction, like the
+    /// fingerprint) rather than random per call, so it never disturbs the
+    /// ordering of IDs from the same [`Cuid2`]. A fixed-width base-36
+    /// encoding of the current millisecond timestamp keeps ordering stable
+    /// across milliseconds, and a sequence counter that immediately follows
+    /// it breaks ties between IDs generated within the same millisecond. That
+    /// sequence resets to zero whenever the millisecond advances, rather than
+    /// rendering a truncated view of an ever-growing global counter, so it
+    /// never wraps back to a lower value within a millisecond no matter how
+    /// long the generator runs. The remaining characters are filled with
+    /// hashed entropy as in [`Cuid2::generate`], though an id of a given
+    /// length carries fewer hashed characters than one from
+    /// [`Cuid2::generate`], since the timestamp and counter take up 13 of them.
+    ///
+    /// See [`Cuid2::with_length`] for a runnable example.
+    pub fn generate_sortable(&self, length: usize) -> Result<String> {
+        if !(SORTABLE_MIN_LENGTH..=MAX_LENGTH).contains(&length) {
+            return Err(CuidError::InvalidLength(
+                length,
+                SORTABLE_MIN_LENGTH,
+                MAX_LENGTH,
+            ));
+        }
+
+        let mut rng = self.rng.borrow_mut();
+
+        let first_letter = self.sortable_letter;
+        let now_ms = self.clock.now_millis()?;
+        let timestamp = render_base36(now_ms as u64, SORTABLE_TIMESTAMP_WIDTH);
+
+        let mut sortable_seq = self.sortable_seq.borrow_mut();
+        let (last_ms, seq) = &mut *sortable_seq;
+        let counter_value = if *last_ms == now_ms {
+            *seq = (*seq + 1).min(MAX_SORTABLE_SEQ - 1);
+            *seq
+        } else {
+            *last_ms = now_ms;
+            *seq = 0;
+            0
+        };
+        let counter = render_base36(counter_value, SORTABLE_COUNTER_WIDTH);
+
+        let entropy_len = length - SORTABLE_MIN_LENGTH;
+        let salt = generate_entropy(&mut *rng, &self.alphabet, entropy_len);
+        let hash_input = format!("{}{}{}{}", timestamp, counter, salt, self.fingerprint);
+        let hashed_entropy = encode_hash(&hash_input, &self.alphabet, entropy_len);
+
+        Ok(format!(
+            "{}{}{}{}",
+            first_letter, timestamp, counter, hashed_entropy
+        ))
+    }
 }
 
 /// Generates a unique identifier similar to CUID2.
@@ -111,24 +462,24 @@ fn generate_fingerprint() -> Result<String> {
 /// let id = generate_cuid(24).unwrap();
 /// assert_eq!(id.len(), 24);
 /// ```
+#[cfg(feature = "std")]
 pub fn generate_cuid(length: usize) -> Result<String> {
     if !(MIN_LENGTH..=MAX_LENGTH).contains(&length) {
         return Err(CuidError::InvalidLength(length, MIN_LENGTH, MAX_LENGTH));
     }
 
-    let first_letter = generate_random_letter()?;
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)?
-        .as_millis()
-        .to_string();
-    let counter_value = COUNTER.fetch_add(1, Ordering::SeqCst).to_string();
-    let salt = generate_entropy(length)?;
-    let fingerprint = generate_fingerprint()?;
-
-    let hash_input = format!("{}{}{}{}", timestamp, salt, counter_value, fingerprint);
-    let hashed = compute_hash(&hash_input, length);
+    thread_local! {
+        static GENERATOR: RefCell<Option<Cuid2<ChaCha20Rng, SystemClock>>> = const { RefCell::new(None) };
+    }
 
-    Ok(format!("{}{}", first_letter, &hashed[1..length]))
+    GENERATOR.with(|cell| {
+        let mut slot = cell.borrow_mut();
+        let needs_new = !matches!(&*slot, Some(generator) if generator.length == length);
+        if needs_new {
+            *slot = Some(Cuid2::with_length(length)?);
+        }
+        slot.as_ref().unwrap().generate()
+    })
 }
 
 /// Generate a CUID with the default length
@@ -143,6 +494,7 @@ pub fn generate_cuid(length: usize) -> Result<String> {
 /// let id = generate().unwrap();
 /// assert_eq!(id.len(), cuid2_rs::DEFAULT_LENGTH);
 /// ```
+#[cfg(feature = "std")]
 pub fn generate() -> Result<String> {
     generate_cuid(DEFAULT_LENGTH)
 }
@@ -179,6 +531,38 @@ pub fn is_valid_cuid(id: &str, min_length: usize, max_length: usize) -> bool {
     starts_with_letter && valid_format && valid_length
 }
 
+/// Validates whether a given ID is composed solely of characters from
+/// `alphabet` and falls within the given length bounds.
+///
+/// Unlike [`is_valid_cuid`], this does not require the id to start with a
+/// lowercase ASCII letter, since callers supplying a custom alphabet (via
+/// [`Cuid2::with_alphabet`]) are opting out of the strict CUID2 charset.
+///
+/// # Examples
+/// ```
+/// use cuid2_rs::{is_valid_cuid_with_alphabet, Cuid2};
+///
+/// let alphabet = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+/// let generator = Cuid2::with_alphabet(alphabet).unwrap();
+/// let id = generator.generate().unwrap();
+/// assert!(is_valid_cuid_with_alphabet(&id, 2, 32, alphabet));
+/// ```
+pub fn is_valid_cuid_with_alphabet(
+    id: &str,
+    min_length: usize,
+    max_length: usize,
+    alphabet: &[u8],
+) -> bool {
+    if id.is_empty() {
+        return false;
+    }
+
+    let valid_length = id.len() >= min_length && id.len() <= max_length;
+    let valid_format = id.bytes().all(|b| alphabet.contains(&b));
+
+    valid_length && valid_format
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,14 +605,16 @@ mod tests {
 
     #[test]
     fn test_generate_entropy() {
-        let entropy = generate_entropy(10).unwrap();
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+        let entropy = generate_entropy(&mut rng, DEFAULT_ALPHABET, 10);
         assert_eq!(entropy.len(), 10);
         assert!(entropy.chars().all(|c| c.is_ascii_alphanumeric()));
     }
 
     #[test]
     fn test_generate_random_letter() {
-        let letter = generate_random_letter().unwrap();
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+        let letter = generate_random_letter(&mut rng, ALPHABET);
         assert!(ALPHABET.contains(&(letter as u8)));
     }
 
@@ -260,4 +646,136 @@ mod tests {
             assert!(id.chars().next().unwrap().is_ascii_lowercase());
         }
     }
+
+    #[test]
+    fn test_cuid2_generate() {
+        let generator = Cuid2::new().unwrap();
+        let id = generator.generate().unwrap();
+        assert!(is_valid_cuid(&id, MIN_LENGTH, MAX_LENGTH));
+        assert_eq!(id.len(), DEFAULT_LENGTH);
+    }
+
+    #[test]
+    fn test_cuid2_generates_distinct_ids_across_calls() {
+        let generator = Cuid2::with_length(16).unwrap();
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+        assert_eq!(first.len(), 16);
+        assert_eq!(second.len(), 16);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_generate_sortable_is_valid_and_ordered() {
+        let generator = Cuid2::new().unwrap();
+        let first = generator.generate_sortable(DEFAULT_LENGTH).unwrap();
+        let second = generator.generate_sortable(DEFAULT_LENGTH).unwrap();
+
+        assert!(is_valid_cuid(&first, MIN_LENGTH, MAX_LENGTH));
+        assert_eq!(first.len(), DEFAULT_LENGTH);
+        assert!(first < second);
+    }
+
+    #[test]
+    fn test_generate_sortable_rejects_too_short_length() {
+        let generator = Cuid2::new().unwrap();
+        let result = generator.generate_sortable(SORTABLE_MIN_LENGTH - 1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generate_sortable_sequence_never_wraps_within_millisecond() {
+        let rng = ChaCha20Rng::seed_from_u64(7);
+        let generator =
+            Cuid2::from_parts(rng, FixedClock(1_700_000_000_000), DEFAULT_LENGTH, "node-1", None)
+                .unwrap();
+
+        let first = generator.generate_sortable(DEFAULT_LENGTH).unwrap();
+        let second = generator.generate_sortable(DEFAULT_LENGTH).unwrap();
+        assert!(first < second, "ids within the same millisecond must still sort in call order");
+
+        *generator.sortable_seq.borrow_mut() = (1_700_000_000_000, MAX_SORTABLE_SEQ - 1);
+        let saturated = generator.generate_sortable(DEFAULT_LENGTH).unwrap();
+        assert!(
+            second <= saturated,
+            "the tie-break sequence must never wrap back below an earlier id in the same millisecond"
+        );
+    }
+
+    #[test]
+    fn test_with_fingerprint_generates_valid_cuid() {
+        let generator = Cuid2::with_fingerprint("container-7f3a").unwrap();
+        let id = generator.generate().unwrap();
+        assert!(is_valid_cuid(&id, MIN_LENGTH, MAX_LENGTH));
+    }
+
+    #[test]
+    fn test_with_fingerprint_is_deterministic_per_custom_value() {
+        let a = Cuid2::with_fingerprint("worker-1").unwrap();
+        let b = Cuid2::with_fingerprint("worker-1").unwrap();
+        assert_eq!(a.fingerprint, b.fingerprint);
+    }
+
+    #[test]
+    fn test_with_alphabet_uses_custom_charset() {
+        const BASE62: &[u8] =
+            b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let generator = Cuid2::with_alphabet(BASE62).unwrap();
+        let id = generator.generate().unwrap();
+
+        assert_eq!(id.len(), DEFAULT_LENGTH);
+        assert!(is_valid_cuid_with_alphabet(&id, MIN_LENGTH, MAX_LENGTH, BASE62));
+    }
+
+    #[test]
+    fn test_is_valid_cuid_with_alphabet_rejects_out_of_set_chars() {
+        assert!(!is_valid_cuid_with_alphabet("a1-2", MIN_LENGTH, MAX_LENGTH, DEFAULT_ALPHABET));
+    }
+
+    #[test]
+    fn test_with_alphabet_rejects_empty_alphabet() {
+        let result = Cuid2::with_alphabet(&[]);
+        assert!(matches!(result, Err(CuidError::EmptyAlphabet)));
+    }
+
+    #[test]
+    fn test_from_parts_rejects_empty_alphabet() {
+        let rng = ChaCha20Rng::seed_from_u64(7);
+        let result = Cuid2::from_parts(
+            rng,
+            FixedClock(0),
+            DEFAULT_LENGTH,
+            "node-1",
+            Some(Vec::new()),
+        );
+        assert!(matches!(result, Err(CuidError::EmptyAlphabet)));
+    }
+
+    /// A fixed-time [`Clock`] standing in for a `no_std` caller's own RTC.
+    struct FixedClock(u128);
+
+    impl Clock for FixedClock {
+        fn now_millis(&self) -> Result<u128> {
+            Ok(self.0)
+        }
+    }
+
+    #[test]
+    fn test_from_parts_with_injected_rng_and_clock() {
+        let rng = ChaCha20Rng::seed_from_u64(7);
+        let generator =
+            Cuid2::from_parts(rng, FixedClock(1_700_000_000_000), DEFAULT_LENGTH, "node-1", None)
+                .unwrap();
+
+        let id = generator.generate().unwrap();
+        assert!(is_valid_cuid(&id, MIN_LENGTH, MAX_LENGTH));
+        assert_eq!(id.len(), DEFAULT_LENGTH);
+    }
+
+    #[test]
+    fn test_from_parts_rejects_invalid_length() {
+        let rng = ChaCha20Rng::seed_from_u64(7);
+        let result = Cuid2::from_parts(rng, FixedClock(0), MAX_LENGTH + 1, "node-1", None);
+        assert!(result.is_err());
+    }
 }
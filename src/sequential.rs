@@ -0,0 +1,158 @@
+//! Fast sequential ID generation, modelled on the prefix+counter scheme used
+//! by NATS NUID. Trades the per-call SHA3 hashing of [`crate::Cuid2`] for a
+//! cheap render of a monotonically advancing counter, at the cost of the
+//! stronger collision guarantees hashing provides.
+
+use rand::rngs::OsRng;
+use rand::{Rng, SeedableRng, TryRngCore};
+use rand_chacha::ChaCha20Rng;
+use std::sync::Mutex;
+
+use crate::{render_base36, Result, ALPHABET};
+
+/// Number of random prefix characters prepended to every id.
+const PREFIX_LEN: usize = 12;
+/// Fixed width of the base-36 sequence tail.
+const SEQ_LEN: usize = 10;
+/// One past the largest sequence value that still fits in [`SEQ_LEN`] base-36
+/// digits.
+const MAX_SEQ: u64 = 36u64.pow(SEQ_LEN as u32);
+/// Lower bound for the randomly chosen per-generator increment.
+const MIN_INCREMENT: u64 = 33;
+/// Upper bound (exclusive) for the randomly chosen per-generator increment.
+const MAX_INCREMENT: u64 = 333;
+
+/// The mutable state behind a [`SequentialGenerator`], updated as one unit
+/// under a single lock so concurrent callers never observe a prefix paired
+/// with the wrong sequence, or race each other into redrawing twice.
+struct State {
+    rng: ChaCha20Rng,
+    prefix: String,
+    sequence: u64,
+    increment: u64,
+}
+
+/// A high-throughput, NUID-style generator that renders a counter instead of
+/// hashing per id.
+///
+/// Each instance owns a random prefix, a sequence counter, and an increment,
+/// all drawn from a persistent RNG at construction. Calling [`Self::next`]
+/// renders the current sequence into a fixed-width base-36 tail and advances
+/// the counter; when the counter would overflow the tail's capacity, the
+/// prefix, sequence, and increment are redrawn. The result always starts with
+/// a letter and is accepted by [`crate::is_valid_cuid`], but unlike
+/// [`crate::Cuid2`] it does not hash any entropy, so it should only be used
+/// where raw throughput matters more than hash-backed collision resistance.
+///
+/// `SequentialGenerator` is `Sync`: the prefix, sequence, increment, and RNG
+/// are guarded by a single mutex, so one generator can be shared (e.g. behind
+/// an `Arc`) across multiple producer threads.
+pub struct SequentialGenerator {
+    state: Mutex<State>,
+}
+
+impl SequentialGenerator {
+    /// Creates a generator with a fresh random prefix, sequence, and
+    /// increment.
+    pub fn new() -> Result<Self> {
+        let seed = OsRng.try_next_u64()?;
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        let (prefix, sequence, increment) = Self::randomize(&mut rng);
+
+        Ok(Self {
+            state: Mutex::new(State {
+                rng,
+                prefix,
+                sequence,
+                increment,
+            }),
+        })
+    }
+
+    /// Draws a new random prefix (starting with a letter), sequence, and
+    /// increment from `rng`.
+    fn randomize(rng: &mut ChaCha20Rng) -> (String, u64, u64) {
+        let mut prefix = String::with_capacity(PREFIX_LEN);
+        prefix.push(ALPHABET[rng.random_range(0..ALPHABET.len())] as char);
+        for _ in 1..PREFIX_LEN {
+            prefix.push(char::from_digit(rng.random_range(0..36) as u32, 36).unwrap());
+        }
+
+        let sequence = rng.random_range(0..MAX_SEQ);
+        let increment = rng.random_range(MIN_INCREMENT..MAX_INCREMENT);
+
+        (prefix, sequence, increment)
+    }
+
+    /// Renders the next id and advances the internal sequence, regenerating
+    /// the prefix/sequence/increment if the advance would overflow the
+    /// sequence tail.
+    pub fn next(&self) -> String {
+        let mut state = self.state.lock().unwrap();
+
+        let id = format!(
+            "{}{}",
+            state.prefix,
+            render_base36(state.sequence, SEQ_LEN)
+        );
+
+        let next_sequence = state.sequence + state.increment;
+        if next_sequence >= MAX_SEQ {
+            let (prefix, sequence, increment) = Self::randomize(&mut state.rng);
+            state.prefix = prefix;
+            state.sequence = sequence;
+            state.increment = increment;
+        } else {
+            state.sequence = next_sequence;
+        }
+
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::is_valid_cuid;
+
+    #[test]
+    fn test_sequential_ids_are_valid_and_distinct() {
+        let generator = SequentialGenerator::new().unwrap();
+        let first = generator.next();
+        let second = generator.next();
+
+        assert!(is_valid_cuid(&first, crate::MIN_LENGTH, crate::MAX_LENGTH));
+        assert!(is_valid_cuid(&second, crate::MIN_LENGTH, crate::MAX_LENGTH));
+        assert_eq!(first.len(), PREFIX_LEN + SEQ_LEN);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_render_base36_is_zero_padded() {
+        assert_eq!(render_base36(0, 4), "0000");
+        assert_eq!(render_base36(35, 4), "000z");
+    }
+
+    #[test]
+    fn test_shared_across_threads_produces_unique_ids() {
+        use std::collections::HashSet;
+        use std::sync::Arc;
+        use std::thread;
+
+        let generator = Arc::new(SequentialGenerator::new().unwrap());
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let generator = Arc::clone(&generator);
+                thread::spawn(move || (0..500).map(|_| generator.next()).collect::<Vec<_>>())
+            })
+            .collect();
+
+        let ids: Vec<String> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+
+        let unique: HashSet<&String> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+    }
+}